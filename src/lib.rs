@@ -2,20 +2,155 @@
 
 use std::{
     borrow::Cow,
-    fs,
+    fmt, fs,
     io::ErrorKind,
     path::{Path, PathBuf},
     sync::Arc,
 };
 
 use actix_files::{Files, NamedFile};
-use actix_service::fn_service;
-use actix_web::dev::{HttpServiceFactory, ResourceDef, ServiceRequest, ServiceResponse};
+use actix_service::{fn_service, forward_ready};
+use actix_web::dev::{
+    HttpServiceFactory, ResourceDef, Service, ServiceRequest, ServiceResponse, Transform,
+};
+use actix_web::http::header::{
+    self, ContentDisposition, DispositionParam, DispositionType, HeaderValue,
+};
+use actix_web::http::StatusCode;
+use actix_web::middleware::DefaultHeaders;
+use actix_web::web;
+use actix_web::HttpResponse;
 use glob::glob;
+use mime::{Mime, Name};
 use path_tree::PathTree;
-use regex::{Captures, Regex};
+use regex::Regex;
 use tracing::{trace, warn};
 
+/// A hook for correcting how served files' `Content-Type` / `Content-Disposition` are
+/// determined, set via [`Spa::mime_override`].
+///
+/// `Disposition` mirrors the hook [`actix_files::Files::mime_override`] exposes: given the
+/// guessed MIME type's top-level name (e.g. `image`, `text`), it decides whether the file
+/// should be displayed inline or downloaded as an attachment.
+///
+/// `ContentType` goes further and lets you override the guessed `Content-Type` itself based on
+/// a path, which `actix-files` has no hook for. This is useful for `next export`
+/// assets (`.webmanifest`, `.mjs`, source maps, ...) that `mime_guess` gets wrong. For the
+/// index, not-found and build-manifest files this crate opens itself, the closure gets the
+/// file's filesystem path; for every response of the `Files` service (including those), it gets
+/// the request's URL path, and a `Some` result from that call replaces the `Content-Type`.
+#[derive(Clone)]
+pub enum MimeOverride {
+    Disposition(Arc<dyn Fn(&Name<'_>) -> DispositionType + Send + Sync>),
+    ContentType(Arc<dyn Fn(&Path) -> Option<Mime> + Send + Sync>),
+}
+
+impl fmt::Debug for MimeOverride {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MimeOverride::Disposition(_) => f.write_str("MimeOverride::Disposition(..)"),
+            MimeOverride::ContentType(_) => f.write_str("MimeOverride::ContentType(..)"),
+        }
+    }
+}
+
+fn apply_mime_override(file: NamedFile, mime_override: &MimeOverride) -> NamedFile {
+    match mime_override {
+        MimeOverride::ContentType(f) => match f(file.path()) {
+            Some(mime) => file.set_content_type(mime),
+            None => file,
+        },
+        MimeOverride::Disposition(f) => {
+            let disposition = f(&file.content_type().type_());
+            let filename = file
+                .path()
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default();
+
+            file.set_content_disposition(ContentDisposition {
+                disposition,
+                parameters: vec![DispositionParam::Filename(filename)],
+            })
+        }
+    }
+}
+
+/// Rewrites the `Content-Type` header of responses produced by the internal `Files` service,
+/// using the closure from [`MimeOverride::ContentType`].
+///
+/// `actix-files` only exposes a disposition hook (mirrored by [`MimeOverride::Disposition`] and
+/// wired straight into `Files::mime_override`), so a `ContentType` override has no way to reach
+/// files `Files` serves directly — it only ever ran for the index/404/build-manifest files opened
+/// by [`serve_index`]. Wrapping the `Files` service with this middleware lets it apply to every
+/// static asset too.
+struct ContentTypeOverride(Arc<dyn Fn(&Path) -> Option<Mime> + Send + Sync>);
+
+impl<S, B> Transform<S, ServiceRequest> for ContentTypeOverride
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = actix_web::Error> + 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Transform = ContentTypeOverrideMiddleware<S>;
+    type InitError = ();
+    type Future = std::future::Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        std::future::ready(Ok(ContentTypeOverrideMiddleware {
+            service,
+            f: self.0.clone(),
+        }))
+    }
+}
+
+struct ContentTypeOverrideMiddleware<S> {
+    service: S,
+    f: Arc<dyn Fn(&Path) -> Option<Mime> + Send + Sync>,
+}
+
+impl<S, B> Service<ServiceRequest> for ContentTypeOverrideMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = actix_web::Error> + 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Future =
+        std::pin::Pin<Box<dyn std::future::Future<Output = Result<Self::Response, Self::Error>>>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let mime = (self.f)(Path::new(req.path()));
+        let fut = self.service.call(req);
+
+        Box::pin(async move {
+            let mut res = fut.await?;
+
+            if let Some(mime) = mime {
+                if let Ok(value) = HeaderValue::from_str(mime.as_ref()) {
+                    res.headers_mut().insert(header::CONTENT_TYPE, value);
+                }
+            }
+
+            Ok(res)
+        })
+    }
+}
+
+/// How exported routes' trailing slashes are normalized, matching Next.js's `trailingSlash`
+/// config option. Set via [`Spa::trailing_slash`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TrailingSlash {
+    /// Serve a route whether or not the request has a trailing slash; never redirect.
+    #[default]
+    Ignore,
+    /// Redirect `/about` to `/about/`. Exported pages are expected at `{route}/index.html`.
+    Always,
+    /// Redirect `/about/` to `/about`. Exported pages are expected at `{route}.html`.
+    Never,
+}
+
 /// Single Page App (SPA) service builder.
 ///
 /// # Examples
@@ -36,8 +171,13 @@ use tracing::{trace, warn};
 #[derive(Debug, Clone)]
 pub struct Spa {
     index_file: Cow<'static, str>,
+    not_found_file: Option<Cow<'static, str>>,
     static_resources_mount: Cow<'static, str>,
     static_resources_location: Cow<'static, str>,
+    immutable_static_cache: bool,
+    cache_control: Option<Cow<'static, str>>,
+    mime_override: Option<MimeOverride>,
+    trailing_slash: TrailingSlash,
 }
 
 impl Spa {
@@ -55,6 +195,20 @@ impl Spa {
         self
     }
 
+    /// Location of the "not found" page.
+    ///
+    /// This file is served with a `404 Not Found` status when a request doesn't match any
+    /// route in the build manifest and doesn't correspond to a real static file. This is
+    /// typically the `404.html` page emitted by `next export`.
+    ///
+    /// The default is `404.html` resolved under [`static_resources_location`][Self::static_resources_location].
+    /// If the file doesn't exist, [`index_file`][Self::index_file] is served instead, matching
+    /// the previous behaviour.
+    pub fn not_found_file(mut self, not_found_file: impl Into<Cow<'static, str>>) -> Self {
+        self.not_found_file = Some(not_found_file.into());
+        self
+    }
+
     /// The URL path prefix that static files should be served from.
     ///
     /// The default is "/". I.e., static files are served from the root URL path.
@@ -78,39 +232,110 @@ impl Spa {
         self
     }
 
+    /// Enables long-lived, immutable caching for fingerprinted `_next/static` assets.
+    ///
+    /// Next.js content-hashes every file under `_next/static`, so it's always safe to cache
+    /// them forever. When enabled, requests whose path begins with
+    /// `{static_resources_mount}/_next/static/` get a
+    /// `Cache-Control: public, max-age=31536000, immutable` header (override the value with
+    /// [`cache_control`][Self::cache_control]). HTML pages served through [`finish`][Self::finish]
+    /// always get `Cache-Control: no-cache` so navigations keep revalidating.
+    ///
+    /// The default is `false`.
+    pub fn immutable_static_cache(mut self, immutable_static_cache: bool) -> Self {
+        self.immutable_static_cache = immutable_static_cache;
+        self
+    }
+
+    /// Overrides the `Cache-Control` header value sent for `_next/static` assets when
+    /// [`immutable_static_cache`][Self::immutable_static_cache] is enabled.
+    ///
+    /// The default is `public, max-age=31536000, immutable`.
+    pub fn cache_control(mut self, cache_control: impl Into<Cow<'static, str>>) -> Self {
+        self.cache_control = Some(cache_control.into());
+        self
+    }
+
+    /// Overrides the guessed MIME type / content disposition of served files.
+    ///
+    /// Applied to both the internal `Files` service and the files opened directly by this crate
+    /// (`index_file`, `not_found_file` and build-manifest matches). [`MimeOverride::Disposition`]
+    /// is wired straight into `Files::mime_override`; [`MimeOverride::ContentType`] additionally
+    /// reaches `Files`-served assets via a wrapping middleware, since `actix-files` has no
+    /// content-type hook of its own. Useful for correcting content types `mime_guess` gets wrong,
+    /// e.g. `.webmanifest` or source map files.
+    pub fn mime_override(mut self, mime_override: MimeOverride) -> Self {
+        self.mime_override = Some(mime_override);
+        self
+    }
+
+    /// Controls how requests' trailing slashes are normalized, matching Next.js's
+    /// `trailingSlash` config option.
+    ///
+    /// In [`TrailingSlash::Always`] mode, `/about` redirects (`301`) to `/about/` and routes are
+    /// resolved from `{route}/index.html`. In [`TrailingSlash::Never`] mode it's the other way
+    /// around. The default is [`TrailingSlash::Ignore`], which serves a route regardless of a
+    /// trailing slash and never redirects, matching the previous behaviour.
+    pub fn trailing_slash(mut self, trailing_slash: TrailingSlash) -> Self {
+        self.trailing_slash = trailing_slash;
+        self
+    }
+
     /// Constructs the service for use in a `.service()` call.
     pub fn finish(self) -> impl HttpServiceFactory {
         let index_file = self.index_file.into_owned();
         let static_resources_location = self.static_resources_location.into_owned();
         let static_resources_location_clone = static_resources_location.clone();
         let static_resources_mount = self.static_resources_mount.into_owned();
+        let not_found_file = self
+            .not_found_file
+            .map(Cow::into_owned)
+            .unwrap_or_else(|| format!("{}/404.html", static_resources_location));
+        let mime_override = self.mime_override.clone();
+        let trailing_slash = self.trailing_slash;
 
         let files = {
             let index_file = index_file.clone();
+            let not_found_file = not_found_file.clone();
+            let mime_override = mime_override.clone();
 
-            let path_tree = Arc::new(
-                find_build_manifest(static_resources_location.clone())
-                    .and_then(|build_manifest_path| fs::read_to_string(build_manifest_path).ok())
-                    .and_then(|build_manifest_content| {
-                        Some(parse_build_manifest(
-                            build_manifest_content,
-                            &static_resources_location,
-                        ))
-                    })
-                    .unwrap_or(PathTree::default()),
-            );
+            let path_tree = Arc::new(build_path_tree(&static_resources_location, trailing_slash));
 
-            Files::new(&static_resources_mount, static_resources_location)
+            let mut files_service = Files::new(&static_resources_mount, static_resources_location)
                 // HACK: FilesService will try to read a directory listing unless index_file is provided
                 // FilesService will fail to load the index_file and will then call our default_handler
-                .index_file("extremely-unlikely-to-exist-!@$%^&*.txt")
-                .default_handler(move |req| serve_index(req, index_file.clone(), path_tree.clone()))
+                .index_file("extremely-unlikely-to-exist-!@$%^&*.txt");
+
+            if let Some(MimeOverride::Disposition(f)) = &mime_override {
+                let f = f.clone();
+                files_service = files_service.mime_override(move |name: &Name<'_>| f(name));
+            }
+
+            files_service.default_handler(move |req| {
+                serve_index(
+                    req,
+                    index_file.clone(),
+                    not_found_file.clone(),
+                    path_tree.clone(),
+                    mime_override.clone(),
+                    trailing_slash,
+                )
+            })
         };
 
         SpaService {
             index_file,
+            not_found_file,
+            static_resources_mount,
             static_resources_location: static_resources_location_clone.clone(),
             files,
+            immutable_static_cache: self.immutable_static_cache,
+            cache_control: self
+                .cache_control
+                .map(Cow::into_owned)
+                .unwrap_or_else(|| "public, max-age=31536000, immutable".to_string()),
+            mime_override: self.mime_override,
+            trailing_slash,
         }
     }
 }
@@ -118,26 +343,63 @@ impl Spa {
 #[derive(Debug)]
 struct SpaService {
     index_file: String,
+    not_found_file: String,
+    static_resources_mount: String,
     static_resources_location: String,
     files: Files,
+    immutable_static_cache: bool,
+    cache_control: String,
+    mime_override: Option<MimeOverride>,
+    trailing_slash: TrailingSlash,
 }
 
 impl HttpServiceFactory for SpaService {
     fn register(self, config: &mut actix_web::dev::AppService) {
-        // let Files register its mount path as-is
-        self.files.register(config);
-
-        let path_tree = Arc::new(
-            find_build_manifest(self.static_resources_location.clone())
-                .and_then(|build_manifest_path| fs::read_to_string(build_manifest_path).ok())
-                .and_then(|build_manifest_content| {
-                    Some(parse_build_manifest(
-                        build_manifest_content,
-                        &self.static_resources_location,
-                    ))
-                })
-                .unwrap_or(PathTree::default()),
-        );
+        if self.immutable_static_cache {
+            let cache_mount = format!(
+                "{}/_next/static",
+                self.static_resources_mount.trim_end_matches('/')
+            );
+            let cache_location = format!(
+                "{}/_next/static",
+                self.static_resources_location.trim_end_matches('/')
+            );
+
+            let mut cache_files = Files::new("/", cache_location);
+            if let Some(MimeOverride::Disposition(f)) = &self.mime_override {
+                let f = f.clone();
+                cache_files = cache_files.mime_override(move |name: &Name<'_>| f(name));
+            }
+
+            // registered before the catch-all `Files` mount so it wins for `_next/static` paths
+            let cache_scope = web::scope(&cache_mount).wrap(
+                DefaultHeaders::new().add((header::CACHE_CONTROL, self.cache_control.clone())),
+            );
+            match &self.mime_override {
+                Some(MimeOverride::ContentType(f)) => cache_scope
+                    .wrap(ContentTypeOverride(f.clone()))
+                    .service(cache_files)
+                    .register(config),
+                _ => cache_scope.service(cache_files).register(config),
+            }
+        }
+
+        // let Files register its mount path as-is, but wrap it so a `ContentType` override (if
+        // any) also reaches files served directly by `Files`, not just `serve_index`'s files
+        match &self.mime_override {
+            Some(MimeOverride::ContentType(f)) => {
+                web::scope("")
+                    .wrap(ContentTypeOverride(f.clone()))
+                    .service(self.files)
+                    .register(config);
+            }
+            _ => self.files.register(config),
+        }
+
+        let path_tree = Arc::new(build_path_tree(
+            &self.static_resources_location,
+            self.trailing_slash,
+        ));
 
         // also define a root prefix handler directed towards our SPA index
         let rdef = ResourceDef::root_prefix("");
@@ -147,7 +409,14 @@ impl HttpServiceFactory for SpaService {
             fn_service(move |req| {
                 trace!("building tree path");
 
-                serve_index(req, self.index_file.clone(), path_tree.clone())
+                serve_index(
+                    req,
+                    self.index_file.clone(),
+                    self.not_found_file.clone(),
+                    path_tree.clone(),
+                    self.mime_override.clone(),
+                    self.trailing_slash,
+                )
             }),
             None,
         );
@@ -157,12 +426,26 @@ impl HttpServiceFactory for SpaService {
 async fn serve_index(
     req: ServiceRequest,
     index_file: String,
+    not_found_file: String,
     path_tree: Arc<PathTree<String>>,
+    mime_override: Option<MimeOverride>,
+    trailing_slash: TrailingSlash,
 ) -> Result<ServiceResponse, actix_web::Error> {
     trace!("serving default SPA page");
+
+    let request_path = req.path().to_string();
     let (req, _) = req.into_parts();
 
-    let file = match path_tree.find(req.path()) {
+    if let Some(location) = trailing_slash_redirect(&request_path, trailing_slash) {
+        let res = HttpResponse::MovedPermanently()
+            .insert_header((header::LOCATION, location))
+            .finish();
+        return Ok(ServiceResponse::new(req, res));
+    }
+
+    let lookup_path = normalized_lookup_path(&request_path);
+
+    let file = match path_tree.find(&lookup_path) {
         Some((h, _)) => match NamedFile::open_async(h).await {
             Ok(f) => Ok(f),
             Err(e) => match e.kind() {
@@ -170,13 +453,85 @@ async fn serve_index(
                 _ => Err(e),
             },
         },
-        None => NamedFile::open_async(&index_file).await,
+        None => match NamedFile::open_async(&not_found_file).await {
+            Ok(f) => Ok(f.set_status_code(StatusCode::NOT_FOUND)),
+            Err(e) => match e.kind() {
+                ErrorKind::NotFound => NamedFile::open_async(&index_file).await,
+                _ => Err(e),
+            },
+        },
     }?;
 
-    let res = file.into_response(&req);
+    let file = match &mime_override {
+        Some(mime_override) => apply_mime_override(file, mime_override),
+        None => file,
+    };
+
+    let mut res = file.into_response(&req);
+    res.headers_mut()
+        .insert(header::CACHE_CONTROL, HeaderValue::from_static("no-cache"));
+
     Ok(ServiceResponse::new(req, res))
 }
 
+/// Strips a trailing slash from `path` for `path_tree` lookups (except the root `/`), so
+/// `/about` and `/about/` resolve to the same route regardless of `trailing_slash` mode.
+fn normalized_lookup_path(path: &str) -> Cow<'_, str> {
+    match path.len() {
+        len if len > 1 && path.ends_with('/') => Cow::Borrowed(&path[..len - 1]),
+        _ => Cow::Borrowed(path),
+    }
+}
+
+/// Returns the canonical URL to `301`-redirect to when `path` doesn't match the configured
+/// `trailing_slash` mode, or `None` if it already does (or the mode is [`TrailingSlash::Ignore`]).
+fn trailing_slash_redirect(path: &str, trailing_slash: TrailingSlash) -> Option<String> {
+    if path == "/" {
+        return None;
+    }
+
+    match trailing_slash {
+        TrailingSlash::Ignore => None,
+        TrailingSlash::Always if !path.ends_with('/') => Some(format!("{}/", path)),
+        TrailingSlash::Never if path.ends_with('/') => Some(path[..path.len() - 1].to_string()),
+        TrailingSlash::Always | TrailingSlash::Never => None,
+    }
+}
+
+/// Builds the route -> exported HTML file lookup, trying the Pages Router's
+/// `_buildManifest.js` first and falling back to the App Router's `app-build-manifest.json`.
+/// Returns an empty tree when neither is found, so every route gets the not-found page (or the
+/// index page when there is no not-found file).
+fn build_path_tree(
+    static_resources_location: &str,
+    trailing_slash: TrailingSlash,
+) -> PathTree<String> {
+    if let Some(build_manifest_path) = find_build_manifest(static_resources_location.to_string()) {
+        if let Ok(build_manifest_content) = fs::read_to_string(build_manifest_path) {
+            return parse_build_manifest(
+                build_manifest_content,
+                static_resources_location,
+                trailing_slash,
+            );
+        }
+    }
+
+    if let Some(app_build_manifest_path) =
+        find_app_build_manifest(static_resources_location.to_string())
+    {
+        if let Ok(app_build_manifest_content) = fs::read_to_string(app_build_manifest_path) {
+            return parse_app_build_manifest(
+                app_build_manifest_content,
+                static_resources_location,
+                trailing_slash,
+            );
+        }
+    }
+
+    warn!("neither _buildManifest.js nor app-build-manifest.json found, no route will match");
+    PathTree::default()
+}
+
 fn find_build_manifest(static_resources_location: String) -> Option<PathBuf> {
     let pattern = format!("{}/_next/**/_buildManifest.js", static_resources_location);
     let entries = glob(&pattern);
@@ -205,9 +560,26 @@ fn find_build_manifest(static_resources_location: String) -> Option<PathBuf> {
     }
 }
 
+/// Resolves the exported HTML file for `route`, following the on-disk layout `next export`
+/// produces for the given `trailing_slash` mode (`{route}.html`, or `{route}/index.html` when
+/// [`TrailingSlash::Always`]).
+fn exported_html_path(route: &str, trailing_slash: TrailingSlash) -> String {
+    if route == "/" {
+        return "index.html".to_string();
+    }
+
+    let trimmed = route.strip_prefix('/').unwrap();
+
+    match trailing_slash {
+        TrailingSlash::Always => format!("{}/index.html", trimmed),
+        TrailingSlash::Ignore | TrailingSlash::Never => format!("{}.html", trimmed),
+    }
+}
+
 fn parse_build_manifest(
     build_manifest: String,
     static_resources_location: &str,
+    trailing_slash: TrailingSlash,
 ) -> PathTree<String> {
     let re = Regex::new(r#""([^,]+)":\s*\["[^,]+"\]"#).unwrap();
     let mut tree = PathTree::new();
@@ -216,40 +588,176 @@ fn parse_build_manifest(
 
     for (_, [path]) in re.captures_iter(&build_manifest).map(|c| c.extract()) {
         let value = resources_path
-            .join(format!(
-                "{}.html",
-                if path == "/" {
-                    "index"
-                } else {
-                    path.strip_prefix("/").unwrap()
-                }
-            ))
+            .join(exported_html_path(path, trailing_slash))
             .to_str()
             .unwrap()
             .to_string();
-        let path = convert_dynamic_path(path).replace(".html", "");
 
-        let _ = tree.insert(&path, value);
+        if let Some(parent_path) = optional_catch_all_parent(path) {
+            // `[[...name]]` matches both the wildcard path and its parent, so insert both.
+            let converted = convert_dynamic_path(path).replace(".html", "");
+            let _ = tree.insert(&converted, value.clone());
+
+            let converted_parent = convert_dynamic_path(&parent_path).replace(".html", "");
+            let _ = tree.insert(&converted_parent, value);
+        } else {
+            let path = convert_dynamic_path(path).replace(".html", "");
+            let _ = tree.insert(&path, value);
+        }
+    }
+
+    tree
+}
+
+fn find_app_build_manifest(static_resources_location: String) -> Option<PathBuf> {
+    let pattern = format!(
+        "{}/_next/**/app-build-manifest.json",
+        static_resources_location
+    );
+    let entries = glob(&pattern);
+
+    match entries {
+        Ok(paths) => {
+            for path in paths {
+                match path {
+                    Ok(p) => {
+                        return Some(p);
+                    }
+                    Err(err) => {
+                        warn!("{}", err);
+                        return None;
+                    }
+                }
+            }
+
+            warn!("app-build-manifest.json not found");
+            return None;
+        }
+        Err(err) => {
+            warn!("{}", err);
+            return None;
+        }
+    }
+}
+
+/// Strips an App Router manifest key's trailing `/page` segment to recover the URL path, e.g.
+/// `/about/page` -> `/about` and `/page` -> `/`. Returns `None` for keys that aren't page
+/// entries (e.g. `/about/layout`).
+fn app_router_route(key: &str) -> Option<&str> {
+    let route = key.strip_suffix("/page")?;
+    Some(if route.is_empty() { "/" } else { route })
+}
+
+/// Parses the App Router's `app-build-manifest.json`. Its route keys are page entries of the
+/// form `/about/page` or `/blog/[slug]/page`; the trailing `/page` segment is stripped to get
+/// the URL path, which is then resolved to the `.html` file `next export` produced for it.
+fn parse_app_build_manifest(
+    build_manifest: String,
+    static_resources_location: &str,
+    trailing_slash: TrailingSlash,
+) -> PathTree<String> {
+    // Real `app-build-manifest.json` entries list multiple, comma-separated chunks (unlike the
+    // Pages Router's single-chunk-per-page manifest), so the chunk array itself is matched with
+    // `[^\]]*` rather than assuming a single comma-free element.
+    let re = Regex::new(r#""([^"]+)":\s*\[[^\]]*\]"#).unwrap();
+    let mut tree = PathTree::new();
+
+    let resources_path = Path::new(static_resources_location);
+
+    for (_, [key]) in re.captures_iter(&build_manifest).map(|c| c.extract()) {
+        let Some(route) = app_router_route(key) else {
+            continue;
+        };
+
+        let html_path = resources_path.join(exported_html_path(route, trailing_slash));
+
+        if !html_path.exists() {
+            warn!(
+                "app router manifest references `{}` but `{}` does not exist, skipping",
+                key,
+                html_path.display()
+            );
+            continue;
+        }
+
+        let value = html_path.to_str().unwrap().to_string();
+
+        if let Some(parent_path) = optional_catch_all_parent(route) {
+            let converted = convert_dynamic_path(route).replace(".html", "");
+            let _ = tree.insert(&converted, value.clone());
+
+            let converted_parent = convert_dynamic_path(&parent_path).replace(".html", "");
+            let _ = tree.insert(&converted_parent, value);
+        } else {
+            let converted = convert_dynamic_path(route).replace(".html", "");
+            let _ = tree.insert(&converted, value);
+        }
     }
 
     tree
 }
 
+/// If `path`'s last segment is an optional catch-all (`[[...name]]`), returns the parent path
+/// with that segment removed (e.g. `/blog/[[...slug]]` -> `/blog`). Returns `None` for every
+/// other path, including a required catch-all (`[...name]`), which has no "parent" route.
+fn optional_catch_all_parent(path: &str) -> Option<String> {
+    let re = Regex::new(r#"^\[\[\.\.\.[^\]]+\]\]$"#).unwrap();
+    let (parent, last) = path.rsplit_once('/')?;
+
+    if !re.is_match(last) {
+        return None;
+    }
+
+    Some(if parent.is_empty() { "/" } else { parent }.to_string())
+}
+
 fn convert_dynamic_path(path: &str) -> String {
-    let re = Regex::new(r#"(?<param>\[[^\]]+\])"#).unwrap();
-    return re
-        .replace_all(path, |caps: &Captures| {
-            format!(":{}", &caps[1].replace("[", "").replace("]", ""))
+    let catch_all_re = Regex::new(r#"^\[\.\.\.(?<param>[^\]]+)\]$"#).unwrap();
+    let optional_catch_all_re = Regex::new(r#"^\[\[\.\.\.(?<param>[^\]]+)\]\]$"#).unwrap();
+    let param_re = Regex::new(r#"^\[(?<param>[^.\]]+)\]$"#).unwrap();
+
+    let segments: Vec<&str> = path.split('/').collect();
+    let last_index = segments.len() - 1;
+
+    segments
+        .iter()
+        .enumerate()
+        .map(|(i, segment)| {
+            if let Some(caps) = catch_all_re
+                .captures(segment)
+                .or_else(|| optional_catch_all_re.captures(segment))
+            {
+                if i != last_index {
+                    warn!(
+                        "catch-all segment `{}` must be the last segment of `{}`, leaving as-is",
+                        segment, path
+                    );
+                    return segment.to_string();
+                }
+
+                return format!("*{}", &caps["param"]);
+            }
+
+            match param_re.captures(segment) {
+                Some(caps) => format!(":{}", &caps["param"]),
+                None => segment.to_string(),
+            }
         })
-        .to_string();
+        .collect::<Vec<_>>()
+        .join("/")
 }
 
 impl Default for Spa {
     fn default() -> Self {
         Self {
             index_file: Cow::Borrowed("./index.html"),
+            not_found_file: None,
             static_resources_mount: Cow::Borrowed("/"),
             static_resources_location: Cow::Borrowed("./"),
+            immutable_static_cache: false,
+            cache_control: None,
+            mime_override: None,
+            trailing_slash: TrailingSlash::default(),
         }
     }
 }
@@ -297,6 +805,19 @@ mod tests {
         assert!(html.contains("Home page"));
     }
 
+    #[actix_web::test]
+    async fn index_has_no_cache_header() {
+        let app = test::init_service(test_app()).await;
+
+        let req = test::TestRequest::default().to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(
+            res.headers().get(header::CACHE_CONTROL).unwrap(),
+            "no-cache"
+        );
+    }
+
     #[actix_web::test]
     async fn returns_page() {
         let app = test::init_service(test_app()).await;
@@ -328,9 +849,38 @@ mod tests {
     }
 
     #[actix_web::test]
-    async fn unknown_page_returns_index() {
+    async fn returns_catch_all_page() {
         let app = test::init_service(test_app()).await;
 
+        let req = test::TestRequest::default()
+            .uri("/blog/a/b/c")
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status(), StatusCode::OK);
+
+        let body = test::read_body(res).await;
+        let html = from_utf8(&body).unwrap();
+        assert!(html.contains("Blog Post"));
+    }
+
+    #[actix_web::test]
+    async fn unknown_page_falls_back_to_index_without_not_found_file() {
+        // `test_app()` leaves `not_found_file` unset, which defaults to
+        // `fixtures/001/404.html` — the same file `unknown_page_returns_not_found_status`
+        // serves. Point it at a file that doesn't exist instead, so this test actually
+        // exercises the "no not-found page" fallback rather than duplicating that one.
+        let app = test::init_service(
+            App::new().service(
+                Spa::default()
+                    .index_file("./fixtures/001/index.html")
+                    .not_found_file("./fixtures/001/does-not-exist.html")
+                    .static_resources_location("./fixtures/001")
+                    .finish(),
+            ),
+        )
+        .await;
+
         let req = test::TestRequest::default().uri("/whatisthis").to_request();
         let res = test::call_service(&app, req).await;
 
@@ -341,6 +891,29 @@ mod tests {
         assert!(html.contains("Home page"));
     }
 
+    #[actix_web::test]
+    async fn unknown_page_returns_not_found_status() {
+        let app = test::init_service(
+            App::new().service(
+                Spa::default()
+                    .index_file("./fixtures/001/index.html")
+                    .not_found_file("./fixtures/001/404.html")
+                    .static_resources_location("./fixtures/001")
+                    .finish(),
+            ),
+        )
+        .await;
+
+        let req = test::TestRequest::default().uri("/whatisthis").to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status(), StatusCode::NOT_FOUND);
+
+        let body = test::read_body(res).await;
+        let html = from_utf8(&body).unwrap();
+        assert!(html.contains("Not Found"));
+    }
+
     #[actix_web::test]
     async fn returns_assets() {
         let app = test::init_service(test_app()).await;
@@ -354,4 +927,261 @@ mod tests {
         let svg = from_utf8(&body).unwrap();
         assert!(svg.contains(r#"<svg xmlns="http://www.w3.org/2000/svg" fill="none""#));
     }
+
+    #[actix_web::test]
+    async fn trailing_slash_always_redirects_then_resolves_page() {
+        let app = test::init_service(
+            App::new().service(
+                Spa::default()
+                    .index_file("./fixtures/001/index.html")
+                    .static_resources_location("./fixtures/001")
+                    .trailing_slash(TrailingSlash::Always)
+                    .finish(),
+            ),
+        )
+        .await;
+
+        let req = test::TestRequest::default().uri("/page").to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status(), StatusCode::MOVED_PERMANENTLY);
+        assert_eq!(res.headers().get(header::LOCATION).unwrap(), "/page/");
+
+        let req = test::TestRequest::default().uri("/page/").to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status(), StatusCode::OK);
+
+        let body = test::read_body(res).await;
+        let html = from_utf8(&body).unwrap();
+        assert!(html.contains("Sample Page"));
+    }
+
+    #[actix_web::test]
+    async fn mime_override_content_type_applies_to_static_assets() {
+        let app = test::init_service(
+            App::new().service(
+                Spa::default()
+                    .index_file("./fixtures/001/index.html")
+                    .static_resources_location("./fixtures/001")
+                    .mime_override(MimeOverride::ContentType(Arc::new(|_: &Path| {
+                        Some(mime::APPLICATION_JSON)
+                    })))
+                    .finish(),
+            ),
+        )
+        .await;
+
+        // `/next.svg` is served straight from the `Files` service, never through `serve_index`,
+        // so this only passes if the override also applies to `Files`-served assets.
+        let req = test::TestRequest::default().uri("/next.svg").to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(
+            res.headers().get(header::CONTENT_TYPE).unwrap(),
+            mime::APPLICATION_JSON.as_ref()
+        );
+    }
+
+    #[actix_web::test]
+    async fn immutable_static_cache_sets_header_for_next_static_assets() {
+        let app = test::init_service(
+            App::new().service(
+                Spa::default()
+                    .index_file("./fixtures/001/index.html")
+                    .static_resources_location("./fixtures/001")
+                    .immutable_static_cache(true)
+                    .finish(),
+            ),
+        )
+        .await;
+
+        let req = test::TestRequest::default()
+            .uri("/_next/static/chunks/app.js")
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(
+            res.headers().get(header::CACHE_CONTROL).unwrap(),
+            "public, max-age=31536000, immutable"
+        );
+
+        let req = test::TestRequest::default().to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(
+            res.headers().get(header::CACHE_CONTROL).unwrap(),
+            "no-cache"
+        );
+    }
+
+    #[actix_web::test]
+    async fn mime_override_sets_content_type() {
+        let app = test::init_service(
+            App::new().service(
+                Spa::default()
+                    .index_file("./fixtures/001/index.html")
+                    .static_resources_location("./fixtures/001")
+                    .mime_override(MimeOverride::ContentType(Arc::new(|_: &Path| {
+                        Some(mime::APPLICATION_JSON)
+                    })))
+                    .finish(),
+            ),
+        )
+        .await;
+
+        let req = test::TestRequest::default().to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(
+            res.headers().get(header::CONTENT_TYPE).unwrap(),
+            mime::APPLICATION_JSON.as_ref()
+        );
+    }
+
+    #[test]
+    fn converts_dynamic_segment() {
+        assert_eq!(convert_dynamic_path("/items/[id]"), "/items/:id");
+    }
+
+    #[test]
+    fn converts_catch_all_segment() {
+        assert_eq!(convert_dynamic_path("/blog/[...slug]"), "/blog/*slug");
+    }
+
+    #[test]
+    fn converts_optional_catch_all_segment() {
+        assert_eq!(convert_dynamic_path("/blog/[[...slug]]"), "/blog/*slug");
+    }
+
+    #[test]
+    fn leaves_non_trailing_catch_all_unconverted() {
+        assert_eq!(convert_dynamic_path("/[...slug]/edit"), "/[...slug]/edit");
+    }
+
+    #[test]
+    fn optional_catch_all_parent_strips_last_segment() {
+        assert_eq!(
+            optional_catch_all_parent("/blog/[[...slug]]"),
+            Some("/blog".to_string())
+        );
+        assert_eq!(optional_catch_all_parent("/blog/[...slug]"), None);
+        assert_eq!(optional_catch_all_parent("/blog/[slug]"), None);
+    }
+
+    #[test]
+    fn app_router_route_strips_trailing_page_segment() {
+        assert_eq!(app_router_route("/page"), Some("/"));
+        assert_eq!(app_router_route("/about/page"), Some("/about"));
+        assert_eq!(app_router_route("/blog/[slug]/page"), Some("/blog/[slug]"));
+        assert_eq!(app_router_route("/about/layout"), None);
+    }
+
+    #[test]
+    fn parses_app_build_manifest_with_multi_chunk_entries() {
+        // `parse_app_build_manifest` checks that the exported `.html` file actually exists, so
+        // this needs real files on disk, not just a manifest string.
+        let dir = std::env::temp_dir().join(format!(
+            "actix-web-nextjs-spa-app-router-test-{}",
+            std::process::id()
+        ));
+        fs::create_dir_all(dir.join("blog")).unwrap();
+        fs::write(dir.join("about.html"), "About page").unwrap();
+        fs::write(dir.join("blog/[slug].html"), "Blog page").unwrap();
+
+        let build_manifest = r#"{
+            "/about/page": [
+                "static/chunks/1.js",
+                "static/chunks/2.js"
+            ],
+            "/blog/[slug]/page": [
+                "static/chunks/3.js",
+                "static/chunks/4.js"
+            ],
+            "/about/layout": [
+                "static/chunks/5.js"
+            ]
+        }"#
+        .to_string();
+
+        let tree = parse_app_build_manifest(
+            build_manifest,
+            dir.to_str().unwrap(),
+            TrailingSlash::Ignore,
+        );
+
+        let (about, _) = tree.find("/about").expect("multi-chunk entry should parse");
+        assert!(about.ends_with("about.html"));
+
+        let (blog, _) = tree
+            .find("/blog/hello-world")
+            .expect("dynamic multi-chunk entry should parse");
+        assert!(blog.ends_with("blog/[slug].html"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn normalized_lookup_path_strips_trailing_slash() {
+        assert_eq!(normalized_lookup_path("/about/"), "/about");
+        assert_eq!(normalized_lookup_path("/about"), "/about");
+        assert_eq!(normalized_lookup_path("/"), "/");
+    }
+
+    #[test]
+    fn trailing_slash_redirect_ignore_never_redirects() {
+        assert_eq!(
+            trailing_slash_redirect("/about", TrailingSlash::Ignore),
+            None
+        );
+        assert_eq!(
+            trailing_slash_redirect("/about/", TrailingSlash::Ignore),
+            None
+        );
+    }
+
+    #[test]
+    fn trailing_slash_redirect_always_adds_slash() {
+        assert_eq!(
+            trailing_slash_redirect("/about", TrailingSlash::Always),
+            Some("/about/".to_string())
+        );
+        assert_eq!(
+            trailing_slash_redirect("/about/", TrailingSlash::Always),
+            None
+        );
+        assert_eq!(trailing_slash_redirect("/", TrailingSlash::Always), None);
+    }
+
+    #[test]
+    fn trailing_slash_redirect_never_strips_slash() {
+        assert_eq!(
+            trailing_slash_redirect("/about/", TrailingSlash::Never),
+            Some("/about".to_string())
+        );
+        assert_eq!(
+            trailing_slash_redirect("/about", TrailingSlash::Never),
+            None
+        );
+        assert_eq!(trailing_slash_redirect("/", TrailingSlash::Never), None);
+    }
+
+    #[test]
+    fn exported_html_path_matches_trailing_slash_mode() {
+        assert_eq!(exported_html_path("/", TrailingSlash::Ignore), "index.html");
+        assert_eq!(
+            exported_html_path("/about", TrailingSlash::Ignore),
+            "about.html"
+        );
+        assert_eq!(
+            exported_html_path("/about", TrailingSlash::Always),
+            "about/index.html"
+        );
+        assert_eq!(
+            exported_html_path("/about", TrailingSlash::Never),
+            "about.html"
+        );
+    }
 }